@@ -8,11 +8,14 @@ use core::{
 pub(crate) mod data;
 pub(crate) mod generation;
 pub(crate) mod index;
+pub(crate) mod map;
 
 #[doc(inline)]
 pub use generation::*;
 #[doc(inline)]
 pub use index::*;
+#[doc(inline)]
+pub use map::*;
 
 use crate::entity::data::{EntityData, RawData};
 
@@ -108,6 +111,84 @@ impl Entity {
         }
     }
 
+    /// Get the little-endian byte representation of this entity.
+    ///
+    /// Unlike [`Entity::to_bits`], this is stable across platforms regardless
+    /// of host endianness, making it suitable for save files and network
+    /// transfer. It is built from [`EntityIndex::get`] and
+    /// [`EntityGen::get`], not from either type's internal bit
+    /// representation, so it also stays stable across crate versions even if
+    /// those internal representations change.
+    #[inline(always)]
+    #[must_use]
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        Entity::to_canonical_bits(self).to_le_bytes()
+    }
+
+    /// Get the big-endian byte representation of this entity.
+    ///
+    /// Unlike [`Entity::to_bits`], this is stable across platforms regardless
+    /// of host endianness, making it suitable for save files and network
+    /// transfer. It is built from [`EntityIndex::get`] and
+    /// [`EntityGen::get`], not from either type's internal bit
+    /// representation, so it also stays stable across crate versions even if
+    /// those internal representations change.
+    #[inline(always)]
+    #[must_use]
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        Entity::to_canonical_bits(self).to_be_bytes()
+    }
+
+    /// Create an [`Entity`] from its little-endian byte representation, as
+    /// produced by [`Entity::to_le_bytes`].
+    ///
+    /// # Returns
+    ///
+    /// This will return [`None`] if `bytes` is not a valid [`Entity`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Option<Entity> {
+        Entity::from_canonical_bits(u64::from_le_bytes(bytes))
+    }
+
+    /// Create an [`Entity`] from its big-endian byte representation, as
+    /// produced by [`Entity::to_be_bytes`].
+    ///
+    /// # Returns
+    ///
+    /// This will return [`None`] if `bytes` is not a valid [`Entity`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Option<Entity> {
+        Entity::from_canonical_bits(u64::from_be_bytes(bytes))
+    }
+
+    /// Pack this entity's stable, public index and generation values into a
+    /// single [`u64`] for the byte codec, independent of either type's
+    /// internal bit representation.
+    #[inline(always)]
+    #[must_use]
+    const fn to_canonical_bits(self) -> u64 {
+        ((self.index().get() as u64) << 32) | (self.generation().get() as u64)
+    }
+
+    /// Inverse of [`Entity::to_canonical_bits`].
+    ///
+    /// # Returns
+    ///
+    /// Returns [`None`] if the packed index or generation is not valid.
+    #[inline(always)]
+    #[must_use]
+    const fn from_canonical_bits(bits: u64) -> Option<Entity> {
+        let index = EntityIndex::new((bits >> 32) as u32);
+        let generation = EntityGen::new(bits as u32);
+
+        match (index, generation) {
+            (Some(index), Some(generation)) => Some(Entity::new(index, generation)),
+            _ => None,
+        }
+    }
+
     /// Returns the index for this [`Entity`].
     #[inline(always)]
     #[must_use]
@@ -202,3 +283,56 @@ impl From<(EntityIndex, EntityGen)> for Entity {
         Entity::new(value.0, value.1)
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Entity {
+    /// Serializes as the canonical little-endian [`u64`] produced by
+    /// [`Entity::to_le_bytes`], so entities survive serialization regardless
+    /// of the producing platform's endianness.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(u64::from_le_bytes(self.to_le_bytes()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Entity {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Entity, D::Error> {
+        let bits = u64::deserialize(deserializer)?;
+
+        Entity::from_le_bytes(bits.to_le_bytes())
+            .ok_or_else(|| serde::de::Error::custom("invalid `Entity` bit representation"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn le_bytes_round_trip() {
+        let entity = Entity::new(EntityIndex::new(7).unwrap(), EntityGen::new(9).unwrap());
+
+        assert_eq!(Entity::from_le_bytes(entity.to_le_bytes()), Some(entity));
+    }
+
+    #[test]
+    fn be_bytes_round_trip() {
+        let entity = Entity::new(EntityIndex::new(7).unwrap(), EntityGen::new(9).unwrap());
+
+        assert_eq!(Entity::from_be_bytes(entity.to_be_bytes()), Some(entity));
+    }
+
+    #[test]
+    fn le_bytes_reject_invalid_index_encoding() {
+        let bytes = ((u32::MAX as u64) << 32).to_le_bytes();
+
+        assert_eq!(Entity::from_le_bytes(bytes), None);
+    }
+
+    #[test]
+    fn be_bytes_reject_invalid_index_encoding() {
+        let bytes = ((u32::MAX as u64) << 32).to_be_bytes();
+
+        assert_eq!(Entity::from_be_bytes(bytes), None);
+    }
+}
@@ -11,6 +11,15 @@ pub struct EntityGen {
     bits: u32,
 }
 
+/// The number of high bits of an [`EntityGen`] reserved for the optional
+/// `kind` tag set up via [`EntityGen::with_kind`].
+const KIND_BITS: u32 = 8;
+/// The mask covering the low bits that make up the real generation counter
+/// once a `kind` tag is in use.
+const GEN_MASK: u32 = u32::MAX >> KIND_BITS;
+/// The mask covering the high bits that make up the `kind` tag.
+const KIND_MASK: u32 = !GEN_MASK;
+
 impl EntityGen {
     /// The minimum [`EntityGen`], `0`.
     pub const MIN: EntityGen = EntityGen::new(0).unwrap();
@@ -55,6 +64,167 @@ impl EntityGen {
     pub const fn get(self) -> u32 {
         self.to_bits()
     }
+
+    /// Advance this generation by one, wrapping from [`EntityGen::MAX`] back
+    /// to [`EntityGen::MIN`].
+    ///
+    /// # Returns
+    ///
+    /// The advanced generation, along with whether it wrapped around. Callers
+    /// should treat a wrap as a signal that this index's generation space is
+    /// exhausted, and that newly issued [`Entity`](super::Entity)s carrying it
+    /// may alias previously-freed ones.
+    ///
+    /// See the hazard note on [`EntityGen::advance_by`] before using this on
+    /// a `kind`-tagged generation.
+    #[inline(always)]
+    #[must_use]
+    pub const fn advance(self) -> (EntityGen, bool) {
+        self.advance_by(1)
+    }
+
+    /// Advance this generation by `n`, wrapping from [`EntityGen::MAX`] back
+    /// to [`EntityGen::MIN`].
+    ///
+    /// # Returns
+    ///
+    /// The advanced generation, along with whether it wrapped around. Callers
+    /// should treat a wrap as a signal that this index's generation space is
+    /// exhausted, and that newly issued [`Entity`](super::Entity)s carrying it
+    /// may alias previously-freed ones.
+    ///
+    /// # Hazard
+    ///
+    /// This operates on the full 32 bits and knows nothing about a `kind` tag
+    /// installed via [`EntityGen::with_kind`] — advancing far enough to carry
+    /// into the tag's bits will silently corrupt it. Use
+    /// [`EntityGen::inc_masked_by`] instead for any [`EntityGen`] that may
+    /// carry a `kind`. This same hazard applies to [`EntityGen::advance`] and
+    /// [`EntityGen::checked_advance_by`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn advance_by(self, n: u32) -> (EntityGen, bool) {
+        let (bits, wrapped) = self.bits.overflowing_add(n);
+
+        (EntityGen { bits }, wrapped)
+    }
+
+    /// Advance this generation by `n`, returning [`None`] if doing so would
+    /// overflow past [`EntityGen::MAX`].
+    ///
+    /// See the hazard note on [`EntityGen::advance_by`] before using this on
+    /// a `kind`-tagged generation.
+    #[inline(always)]
+    #[must_use]
+    pub const fn checked_advance_by(self, n: u32) -> Option<EntityGen> {
+        match self.bits.checked_add(n) {
+            Some(bits) => Some(EntityGen { bits }),
+            None => None,
+        }
+    }
+
+    /// Create a new [`EntityGen`] that reserves its top bits for `kind`,
+    /// leaving the rest as the real generation counter.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`None`] if `gen` does not fit within the bits left over after
+    /// reserving space for `kind`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn with_kind(kind: u8, gen: u32) -> Option<EntityGen> {
+        if gen > GEN_MASK {
+            return None;
+        }
+
+        Some(EntityGen {
+            bits: ((kind as u32) << (32 - KIND_BITS)) | gen,
+        })
+    }
+
+    /// Get the `kind` tag stored in this generation's top bits.
+    ///
+    /// This is only meaningful for [`EntityGen`]s created via
+    /// [`EntityGen::with_kind`]; otherwise it reads whatever the top bits
+    /// happen to hold.
+    #[inline(always)]
+    #[must_use]
+    pub const fn kind(self) -> u8 {
+        (self.bits >> (32 - KIND_BITS)) as u8
+    }
+
+    /// Advance only the low, non-`kind` bits of this generation by `n`,
+    /// wrapping within their reduced range and leaving the `kind` tag
+    /// untouched.
+    #[inline(always)]
+    #[must_use]
+    pub const fn inc_masked_by(self, n: u32) -> EntityGen {
+        let kind_bits = self.bits & KIND_MASK;
+        let gen_bits = (self.bits & GEN_MASK).wrapping_add(n) & GEN_MASK;
+
+        EntityGen {
+            bits: kind_bits | gen_bits,
+        }
+    }
+
+    /// Advance only the low, non-`kind` bits of this generation by `n`,
+    /// returning [`None`] if doing so would overflow past the reduced range
+    /// left over after reserving space for `kind`, rather than wrapping.
+    #[inline(always)]
+    #[must_use]
+    pub const fn checked_inc_masked_by(self, n: u32) -> Option<EntityGen> {
+        let kind_bits = self.bits & KIND_MASK;
+        let gen_bits = self.bits & GEN_MASK;
+
+        match gen_bits.checked_add(n) {
+            Some(gen_bits) if gen_bits <= GEN_MASK => Some(EntityGen {
+                bits: kind_bits | gen_bits,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Get the little-endian byte representation of this generation's bits,
+    /// as returned by [`EntityGen::to_bits`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn to_le_bytes(self) -> [u8; 4] {
+        self.to_bits().to_le_bytes()
+    }
+
+    /// Get the big-endian byte representation of this generation's bits, as
+    /// returned by [`EntityGen::to_bits`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn to_be_bytes(self) -> [u8; 4] {
+        self.to_bits().to_be_bytes()
+    }
+
+    /// Create an [`EntityGen`] from the little-endian byte representation of
+    /// its bits, as produced by [`EntityGen::to_le_bytes`].
+    ///
+    /// # Returns
+    ///
+    /// Currently this never returns [`None`], but this is subject to change
+    /// in the future.
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_le_bytes(bytes: [u8; 4]) -> Option<EntityGen> {
+        EntityGen::from_bits(u32::from_le_bytes(bytes))
+    }
+
+    /// Create an [`EntityGen`] from the big-endian byte representation of its
+    /// bits, as produced by [`EntityGen::to_be_bytes`].
+    ///
+    /// # Returns
+    ///
+    /// Currently this never returns [`None`], but this is subject to change
+    /// in the future.
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_be_bytes(bytes: [u8; 4]) -> Option<EntityGen> {
+        EntityGen::from_bits(u32::from_be_bytes(bytes))
+    }
 }
 
 impl fmt::Debug for EntityGen {
@@ -77,3 +247,71 @@ impl Default for EntityGen {
         EntityGen::MIN
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_does_not_wrap_below_max() {
+        let (gen, wrapped) = EntityGen::new(41).unwrap().advance();
+
+        assert_eq!(gen.get(), 42);
+        assert!(!wrapped);
+    }
+
+    #[test]
+    fn advance_wraps_at_max() {
+        let (gen, wrapped) = EntityGen::MAX.advance();
+
+        assert_eq!(gen, EntityGen::MIN);
+        assert!(wrapped);
+    }
+
+    #[test]
+    fn advance_by_wraps_at_max() {
+        let (gen, wrapped) = EntityGen::MAX.advance_by(2);
+
+        assert_eq!(gen.get(), 1);
+        assert!(wrapped);
+    }
+
+    #[test]
+    fn with_kind_round_trips_kind_and_gen() {
+        let gen = EntityGen::with_kind(0x7, 123).unwrap();
+
+        assert_eq!(gen.kind(), 0x7);
+        assert_eq!(gen.get() & GEN_MASK, 123);
+    }
+
+    #[test]
+    fn with_kind_rejects_gen_outside_reduced_range() {
+        assert!(EntityGen::with_kind(0, GEN_MASK + 1).is_none());
+    }
+
+    #[test]
+    fn inc_masked_by_preserves_kind_and_advances_gen() {
+        let gen = EntityGen::with_kind(0x42, 10).unwrap();
+        let advanced = gen.inc_masked_by(5);
+
+        assert_eq!(advanced.kind(), 0x42);
+        assert_eq!(advanced.get() & GEN_MASK, 15);
+    }
+
+    #[test]
+    fn inc_masked_by_wraps_within_reduced_range_without_disturbing_kind() {
+        let gen = EntityGen::with_kind(0x42, GEN_MASK).unwrap();
+        let advanced = gen.inc_masked_by(1);
+
+        assert_eq!(advanced.kind(), 0x42);
+        assert_eq!(advanced.get() & GEN_MASK, 0);
+    }
+
+    #[test]
+    fn checked_inc_masked_by_detects_exhaustion() {
+        let gen = EntityGen::with_kind(0x42, GEN_MASK).unwrap();
+
+        assert!(gen.checked_inc_masked_by(1).is_none());
+        assert!(gen.checked_inc_masked_by(0).is_some());
+    }
+}
@@ -73,6 +73,48 @@ impl EntityIndex {
     pub const fn is_placeholder(self) -> bool {
         matches!(self, EntityIndex::PLACEHOLDER)
     }
+
+    /// Get the little-endian byte representation of this index's bits, as
+    /// returned by [`EntityIndex::to_bits`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn to_le_bytes(self) -> [u8; 4] {
+        self.to_bits().to_le_bytes()
+    }
+
+    /// Get the big-endian byte representation of this index's bits, as
+    /// returned by [`EntityIndex::to_bits`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn to_be_bytes(self) -> [u8; 4] {
+        self.to_bits().to_be_bytes()
+    }
+
+    /// Create an [`EntityIndex`] from the little-endian byte representation
+    /// of its bits, as produced by [`EntityIndex::to_le_bytes`].
+    ///
+    /// # Returns
+    ///
+    /// Returns [`None`] if `bytes` is not a valid bit representation for an
+    /// [`EntityIndex`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_le_bytes(bytes: [u8; 4]) -> Option<EntityIndex> {
+        EntityIndex::from_bits(u32::from_le_bytes(bytes))
+    }
+
+    /// Create an [`EntityIndex`] from the big-endian byte representation of
+    /// its bits, as produced by [`EntityIndex::to_be_bytes`].
+    ///
+    /// # Returns
+    ///
+    /// Returns [`None`] if `bytes` is not a valid bit representation for an
+    /// [`EntityIndex`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_be_bytes(bytes: [u8; 4]) -> Option<EntityIndex> {
+        EntityIndex::from_bits(u32::from_be_bytes(bytes))
+    }
 }
 
 impl fmt::Debug for EntityIndex {
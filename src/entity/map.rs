@@ -0,0 +1,144 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use hashbrown::HashMap;
+
+use crate::entity::Entity;
+
+/// A policy for remapping [`Entity`] values from one allocator/world to another.
+///
+/// Implementors decide what a source [`Entity`] should become in the target
+/// allocator, e.g. when merging a deserialized scene into a live world whose
+/// indices are already occupied. See [`EntityMapper`] for the default policy.
+pub trait EntityMap {
+    /// Map `entity` from the source allocator to its counterpart in the target
+    /// allocator.
+    fn map_entity(&mut self, entity: Entity) -> Entity;
+}
+
+/// Something which holds [`Entity`] fields that can be remapped from one
+/// allocator/world to another via an [`EntityMap`].
+pub trait MapEntities {
+    /// Walk every [`Entity`] held by `self` and replace it with its mapped
+    /// counterpart, as produced by `mapper`.
+    fn map_entities<M: EntityMap>(&mut self, mapper: &mut M);
+}
+
+impl MapEntities for Entity {
+    #[inline]
+    fn map_entities<M: EntityMap>(&mut self, mapper: &mut M) {
+        *self = mapper.map_entity(*self);
+    }
+}
+
+impl MapEntities for Option<Entity> {
+    #[inline]
+    fn map_entities<M: EntityMap>(&mut self, mapper: &mut M) {
+        if let Some(entity) = self {
+            entity.map_entities(mapper);
+        }
+    }
+}
+
+impl MapEntities for Vec<Entity> {
+    #[inline]
+    fn map_entities<M: EntityMap>(&mut self, mapper: &mut M) {
+        for entity in self {
+            entity.map_entities(mapper);
+        }
+    }
+}
+
+impl<const N: usize> MapEntities for [Entity; N] {
+    #[inline]
+    fn map_entities<M: EntityMap>(&mut self, mapper: &mut M) {
+        for entity in self {
+            entity.map_entities(mapper);
+        }
+    }
+}
+
+/// The default [`EntityMap`] policy: remembers every source-to-target pairing
+/// it has produced, and mints new targets that can never collide with a
+/// living entity.
+///
+/// New targets all share `dead_start`'s [`EntityIndex`](super::EntityIndex)
+/// and advance its generation by a running counter, so repeated lookups of
+/// the same source [`Entity`] are stable and freshly-minted targets are
+/// monotonically distinct.
+#[derive(Debug, Clone)]
+pub struct EntityMapper {
+    map: HashMap<Entity, Entity>,
+    dead_start: Entity,
+    generations: u32,
+}
+
+impl EntityMapper {
+    /// Create a new, empty [`EntityMapper`] that mints targets starting from
+    /// `dead_start`'s index and generation.
+    ///
+    /// `dead_start` should be an [`Entity`] whose index is not, and will never
+    /// be, alive in the target allocator.
+    #[inline]
+    #[must_use]
+    pub fn new(dead_start: Entity) -> EntityMapper {
+        EntityMapper {
+            map: HashMap::new(),
+            dead_start,
+            generations: 0,
+        }
+    }
+
+    /// Get the entity this mapper has paired `source` with, if any.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, source: Entity) -> Option<Entity> {
+        self.map.get(&source).copied()
+    }
+}
+
+impl EntityMap for EntityMapper {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        if let Some(&mapped) = self.map.get(&entity) {
+            return mapped;
+        }
+
+        // `checked_inc_masked_by` rather than `advance_by`: `dead_start`'s
+        // generation may carry a `kind` tag (see `EntityGen::with_kind`), so
+        // we must never disturb it while running the counter, and we must
+        // never silently wrap it either, since a wrapped counter would remap
+        // two different source entities onto the same target and break the
+        // "freshly-minted targets are monotonically distinct" invariant.
+        let generation = self
+            .dead_start
+            .generation()
+            .checked_inc_masked_by(self.generations)
+            .expect("EntityMapper generation counter exhausted");
+        let mapped = Entity::new(self.dead_start.index(), generation);
+
+        self.map.insert(entity, mapped);
+        self.generations += 1;
+
+        mapped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{EntityGen, EntityIndex};
+
+    #[test]
+    #[should_panic(expected = "EntityMapper generation counter exhausted")]
+    fn map_entity_panics_once_masked_generation_space_is_exhausted() {
+        let dead_start = Entity::new(
+            EntityIndex::new(0).unwrap(),
+            EntityGen::with_kind(0, 0).unwrap(),
+        );
+        let mut mapper = EntityMapper::new(dead_start);
+        mapper.generations = u32::MAX;
+
+        mapper.map_entity(Entity::PLACEHOLDER);
+    }
+}